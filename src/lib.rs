@@ -20,24 +20,433 @@
 
 use std::fmt;
 use std::collections::HashSet;
-use rand::{rngs::OsRng, seq::SliceRandom};
+use rand::{rngs::OsRng, Rng};
 
 /// The list of dictionary words.
 // the wordlist JSON also happens to be valid Rust syntax for an array constant.
 pub const WORDS: &'static [&'static str] = &include!("../res/wordlist.json");
 
+/// A dictionary of words together with a membership index, used to generate and
+/// validate word-based phrases. Build one with `Wordlist::builtin()` for the
+/// bundled 7,530-word dictionary, or `Wordlist::from_lines` to load a custom one
+/// (e.g. an EFF or locale-specific wordlist). Always contains at least one word;
+/// the constructors panic otherwise.
+pub struct Wordlist {
+	words: Vec<String>,
+	index: HashSet<String>,
+}
+
+impl Wordlist {
+	/// The built-in dictionary of 7,530 words.
+	pub fn builtin() -> Self {
+		Wordlist::new(WORDS.iter().map(|word| word.to_string()).collect())
+	}
+
+	/// Build a wordlist from a newline-separated list of words. Lines are trimmed,
+	/// so Windows CRLF line endings don't leak into the dictionary.
+	///
+	/// Panics if `text` contains no non-empty lines; a `Wordlist` must have at
+	/// least one word.
+	pub fn from_lines(text: &str) -> Self {
+		Wordlist::new(text.lines().map(|line| line.trim().to_string()).filter(|line| !line.is_empty()).collect())
+	}
+
+	fn new(words: Vec<String>) -> Self {
+		assert!(!words.is_empty(), "Wordlist must contain at least one word");
+		let index = words.iter().cloned().collect();
+		Wordlist { words, index }
+	}
+
+	/// Generate a string which is a random phrase of a number of words chosen from
+	/// this dictionary, joined by single spaces. Words are used exactly as they
+	/// appear in the dictionary.
+	pub fn random_phrase(&self, no_of_words: usize) -> String {
+		self.random_phrase_with(&mut OsRng, no_of_words)
+	}
+
+	/// As `random_phrase`, but drawing word indices from `rng` instead of the OS
+	/// CSPRNG. Feeding a seeded `Rng` makes the generated phrase fully
+	/// reproducible, which is useful for test vectors and golden-file checks.
+	pub fn random_phrase_with<R: Rng + ?Sized>(&self, rng: &mut R, no_of_words: usize) -> String {
+		(0..no_of_words).map(|_| self.words[rng.gen_range(0..self.words.len())].as_str())
+			.fold(String::new(), |mut acc, word| {
+				acc.push_str(" ");
+				acc.push_str(word);
+				acc
+			}).trim_start().to_owned()
+	}
+
+	/// Validates given phrase against this dictionary and checks if:
+	/// 1. All the words are coming from the dictionary.
+	/// 2. There are at least `expected_no_of_words` in the phrase.
+	pub fn validate_phrase(&self, phrase: &str, expected_no_of_words: usize) -> Result<(), Error> {
+		let mut len = 0;
+		for word in phrase.split_whitespace() {
+			len += 1;
+			if !self.index.contains(word) {
+				return Err(Error::WordNotFromDictionary(word.into()));
+			}
+		}
+
+		if len < expected_no_of_words {
+			return Err(Error::PhraseTooShort(len));
+		}
+
+		return Ok(());
+	}
+
+	/// Compute the Shannon entropy, in bits, of a phrase of `no_of_words` words
+	/// drawn uniformly at random from this dictionary.
+	pub fn entropy_bits(&self, no_of_words: usize) -> f64 {
+		entropy_bits_for_dict_len(no_of_words, self.words.len())
+	}
+
+	/// Generate a phrase styled per `style`: separator, capitalization, and an
+	/// optional trailing digit/symbol group.
+	pub fn styled_phrase(&self, no_of_words: usize, style: &PhraseStyle) -> String {
+		self.styled_phrase_with(&mut OsRng, no_of_words, style)
+	}
+
+	/// As `styled_phrase`, but drawing from the given random number generator.
+	pub fn styled_phrase_with<R: Rng + ?Sized>(&self, rng: &mut R, no_of_words: usize, style: &PhraseStyle) -> String {
+		let mut words: Vec<String> = (0..no_of_words)
+			.map(|_| self.words[rng.gen_range(0..self.words.len())].clone())
+			.collect();
+
+		for word in words.iter_mut() {
+			*word = word.to_lowercase();
+		}
+
+		match style.capitalization {
+			Capitalization::Lower => {}
+			Capitalization::Title => {
+				for word in words.iter_mut() {
+					*word = title_case(word);
+				}
+			}
+			Capitalization::Upper => {
+				for word in words.iter_mut() {
+					*word = word.to_uppercase();
+				}
+			}
+			Capitalization::RandomWord => {
+				if !words.is_empty() {
+					let idx = rng.gen_range(0..words.len());
+					words[idx] = title_case(&words[idx]);
+				}
+			}
+		}
+
+		let mut phrase = words.join(style.separator.as_str());
+
+		for _ in 0..style.append_digits {
+			phrase.push(std::char::from_digit(rng.gen_range(0..10), 10).unwrap());
+		}
+		for _ in 0..style.append_symbols {
+			phrase.push(SYMBOLS[rng.gen_range(0..SYMBOLS.len())]);
+		}
+
+		phrase
+	}
+
+	/// Validates a phrase produced by `styled_phrase`/`styled_phrase_with` using
+	/// the given `style`, by reversing the separator, capitalization, and
+	/// appended digit/symbol group before checking dictionary membership.
+	///
+	/// `Separator::None` cannot be reversed unambiguously, so styles using it
+	/// always fail validation with `Error::StyleNotReversible`.
+	pub fn validate_styled_phrase(&self, phrase: &str, expected_no_of_words: usize, style: &PhraseStyle) -> Result<(), Error> {
+		if style.separator == Separator::None {
+			return Err(Error::StyleNotReversible);
+		}
+
+		let body = strip_appended_group(phrase, style.append_digits, style.append_symbols)?;
+		let lower = body.to_lowercase();
+
+		let mut len = 0;
+		for word in lower.split(style.separator.as_str()).filter(|word| !word.is_empty()) {
+			len += 1;
+			if !self.index.contains(word) && !self.words.iter().any(|known| known.to_lowercase() == word) {
+				return Err(Error::WordNotFromDictionary(word.into()));
+			}
+		}
+
+		if len < expected_no_of_words {
+			return Err(Error::PhraseTooShort(len));
+		}
+
+		Ok(())
+	}
+
+	/// Generate a phrase by deterministically mapping a sequence of physical
+	/// d6 dice rolls to words, so a phrase can be produced on an air-gapped
+	/// machine with no trust placed in any RNG.
+	///
+	/// Each `roll` must be in `1..=6`. Rolls are grouped into chunks of
+	/// `dice_chunk_size(self.words.len())` rolls, each chunk interpreted as a
+	/// base-6 integer; chunks landing in the unused tail `[len, 6^chunk_size)`
+	/// are rejected (not reduced via modulo) to keep the word distribution
+	/// uniform. For the built-in 7,530-word dictionary this needs 5 rolls per
+	/// word, so a 12-word phrase needs 60 rolls (more if any chunks land in the
+	/// rejected tail and must be re-rolled).
+	pub fn phrase_from_dice(&self, rolls: &[u8], no_of_words: usize) -> Result<String, Error> {
+		let chunk_size = dice_chunk_size(self.words.len());
+		let mut words = Vec::with_capacity(no_of_words);
+		let mut pos = 0;
+
+		while words.len() < no_of_words {
+			if pos + chunk_size > rolls.len() {
+				return Err(Error::NotEnoughDiceRolls(chunk_size * no_of_words));
+			}
+
+			let mut value = 0usize;
+			for &roll in &rolls[pos..pos + chunk_size] {
+				if !(1..=6).contains(&roll) {
+					return Err(Error::InvalidDiceRoll(roll));
+				}
+				value = value * 6 + (roll as usize - 1);
+			}
+			pos += chunk_size;
+
+			// the tail [len, 6^chunk_size) would bias low word indices; reject and re-roll
+			if value < self.words.len() {
+				words.push(self.words[value].as_str());
+			}
+		}
+
+		Ok(words.join(" "))
+	}
+
+	/// Draw `samples` single-word picks from this dictionary using `rng` and run
+	/// a chi-square goodness-of-fit test against the uniform distribution over
+	/// the dictionary.
+	///
+	/// This guards against a subtly non-uniform index mapping - exactly the kind
+	/// of off-by-one bias that skews word selection when the wrong range bound
+	/// is used - and gives integrators a way to assert generator health in CI
+	/// without pulling a stats crate into this library. Compare the returned
+	/// `chi_square` statistic against a critical value for `degrees_of_freedom`
+	/// at a chosen significance level.
+	pub fn check_uniformity<R: Rng + ?Sized>(&self, rng: &mut R, samples: usize) -> UniformityReport {
+		let mut counts = vec![0usize; self.words.len()];
+		for _ in 0..samples {
+			counts[rng.gen_range(0..self.words.len())] += 1;
+		}
+
+		let expected = samples as f64 / self.words.len() as f64;
+		let chi_square = counts.iter().map(|&observed| {
+			let diff = observed as f64 - expected;
+			diff * diff / expected
+		}).sum();
+
+		UniformityReport {
+			chi_square,
+			degrees_of_freedom: self.words.len() - 1,
+		}
+	}
+
+	/// Fill a mask template with dictionary words, digits, symbols, and letters.
+	///
+	/// `?w` draws a dictionary word, `?d` a random digit, `?s` a random symbol,
+	/// `?u`/`?l` a random upper/lowercase letter; any other character is passed
+	/// through literally. For example `?w-?w-?d?d?s` yields `brain-vault-47!`.
+	/// Returns `Error::InvalidMask` if `mask` ends with a bare `?` or uses an
+	/// unrecognized placeholder letter.
+	pub fn phrase_from_mask<R: Rng + ?Sized>(&self, mask: &str, rng: &mut R) -> Result<String, Error> {
+		let mut out = String::with_capacity(mask.len());
+		let mut chars = mask.chars();
+
+		while let Some(c) = chars.next() {
+			if c != '?' {
+				out.push(c);
+				continue;
+			}
+
+			match chars.next() {
+				Some('w') => out.push_str(&self.words[rng.gen_range(0..self.words.len())]),
+				Some('d') => out.push(std::char::from_digit(rng.gen_range(0..10), 10).unwrap()),
+				Some('s') => out.push(SYMBOLS[rng.gen_range(0..SYMBOLS.len())]),
+				Some('u') => out.push((b'A' + rng.gen_range(0..26)) as char),
+				Some('l') => out.push((b'a' + rng.gen_range(0..26)) as char),
+				_ => return Err(Error::InvalidMask(mask.to_owned())),
+			}
+		}
+
+		Ok(out)
+	}
+
+	/// Compute the Shannon entropy, in bits, of phrases produced by
+	/// `phrase_from_mask` for the given `mask`, summing the per-field entropy of
+	/// each placeholder (`log2(dict_len)` per `?w`, `log2(10)` per `?d`, and so
+	/// on). Literal characters contribute no entropy.
+	pub fn mask_entropy_bits(&self, mask: &str) -> Result<f64, Error> {
+		let mut bits = 0.0;
+		let mut chars = mask.chars();
+
+		while let Some(c) = chars.next() {
+			if c != '?' {
+				continue;
+			}
+
+			bits += match chars.next() {
+				Some('w') => (self.words.len() as f64).log2(),
+				Some('d') => 10f64.log2(),
+				Some('s') => (SYMBOLS.len() as f64).log2(),
+				Some('u') | Some('l') => 26f64.log2(),
+				_ => return Err(Error::InvalidMask(mask.to_owned())),
+			};
+		}
+
+		Ok(bits)
+	}
+}
+
+/// Result of a chi-square goodness-of-fit self-check against the uniform
+/// distribution over a dictionary. See `Wordlist::check_uniformity`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UniformityReport {
+	/// The chi-square statistic, `sum((observed_i - expected)^2 / expected)`.
+	pub chi_square: f64,
+	/// Degrees of freedom, `dict_len - 1`.
+	pub degrees_of_freedom: usize,
+}
+
+/// Number of d6 rolls needed to index a dictionary of `dict_len` words without
+/// bias, i.e. the smallest `k >= 1` such that `6^k >= dict_len`.
+fn dice_chunk_size(dict_len: usize) -> usize {
+	let mut chunk_size = 1;
+	while 6usize.pow(chunk_size as u32) < dict_len {
+		chunk_size += 1;
+	}
+	chunk_size
+}
+
+fn title_case(word: &str) -> String {
+	let mut chars = word.chars();
+	match chars.next() {
+		Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+		None => String::new(),
+	}
+}
+
+fn strip_appended_group(phrase: &str, digits: usize, symbols: usize) -> Result<&str, Error> {
+	let total = digits + symbols;
+	if phrase.len() < total {
+		return Err(Error::PhraseTooShort(0));
+	}
+	Ok(&phrase[..phrase.len() - total])
+}
+
+const SYMBOLS: &[char] = &['!', '@', '#', '$', '%', '^', '&', '*', '?', '+'];
+
+/// Separator inserted between words of a styled phrase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Separator {
+	/// A single space character.
+	Space,
+	/// A hyphen character.
+	Hyphen,
+	/// An underscore character.
+	Underscore,
+	/// No separator; words are joined directly.
+	None,
+}
+
+impl Separator {
+	fn as_str(&self) -> &'static str {
+		match *self {
+			Separator::Space => " ",
+			Separator::Hyphen => "-",
+			Separator::Underscore => "_",
+			Separator::None => "",
+		}
+	}
+}
+
+/// Capitalization applied to the words of a styled phrase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Capitalization {
+	/// All words lowercase (the default `random_phrase` behaviour).
+	Lower,
+	/// Title Case: the first letter of every word is capitalized.
+	Title,
+	/// ALL CAPS.
+	Upper,
+	/// Exactly one randomly chosen word is capitalized, the rest stay lowercase.
+	RandomWord,
+}
+
+/// Configuration for `styled_phrase`/`Wordlist::styled_phrase`: separator,
+/// capitalization, and an optional trailing digit/symbol group appended to
+/// satisfy site password policies.
+#[derive(Debug, Clone)]
+pub struct PhraseStyle {
+	/// Separator placed between words.
+	pub separator: Separator,
+	/// Capitalization mode applied to the words.
+	pub capitalization: Capitalization,
+	/// Number of digits appended after the last word (0 for none).
+	pub append_digits: usize,
+	/// Number of symbol characters appended after the digits (0 for none).
+	pub append_symbols: usize,
+}
+
+impl Default for PhraseStyle {
+	fn default() -> Self {
+		PhraseStyle {
+			separator: Separator::Space,
+			capitalization: Capitalization::Lower,
+			append_digits: 0,
+			append_symbols: 0,
+		}
+	}
+}
+
+fn builtin_wordlist() -> &'static Wordlist {
+	lazy_static::lazy_static! {
+		static ref BUILTIN: Wordlist = Wordlist::builtin();
+	}
+	&BUILTIN
+}
+
 /// Generate a string which is a random phrase of a number of lowercase words.
 ///
 /// `words` is the number of words, chosen from a dictionary of 7,530. An value of
 /// 12 gives 155 bits of entropy (almost saturating address space); 20 gives 258 bits
 /// which is enough to saturate 32-byte key space
 pub fn random_phrase(no_of_words: usize) -> String {
-	let mut rng = OsRng;
-	(0..no_of_words).map(|_| WORDS.choose(&mut rng).unwrap()).fold(String::new(), |mut acc, word| {
-		acc.push_str(" ");
-		acc.push_str(word);
-		acc
-	}).trim_start().to_owned()
+	builtin_wordlist().random_phrase(no_of_words)
+}
+
+/// Generate a string which is a random phrase of a number of lowercase words,
+/// drawing word indices from `rng` instead of the OS CSPRNG.
+///
+/// Feeding a seeded `Rng` (e.g. `ChaCha20Rng::from_seed(...)`) makes the generated
+/// phrase fully reproducible, which is useful for test vectors and golden-file
+/// checks. Production code should use `random_phrase` instead, which always draws
+/// from `OsRng`.
+pub fn random_phrase_with<R: Rng + ?Sized>(rng: &mut R, no_of_words: usize) -> String {
+	builtin_wordlist().random_phrase_with(rng, no_of_words)
+}
+
+/// Compute the Shannon entropy, in bits, of a phrase of `no_of_words` words drawn
+/// uniformly at random from the built-in dictionary.
+pub fn phrase_entropy_bits(no_of_words: usize) -> f64 {
+	builtin_wordlist().entropy_bits(no_of_words)
+}
+
+/// Compute the Shannon entropy, in bits, of a phrase of `no_of_words` words drawn
+/// uniformly at random from a dictionary of `dict_len` words. Useful for custom
+/// or locale-specific wordlists that don't match `WORDS.len()`.
+pub fn entropy_bits_for_dict_len(no_of_words: usize, dict_len: usize) -> f64 {
+	no_of_words as f64 * (dict_len as f64).log2()
+}
+
+/// Compute the minimum number of words, chosen from the built-in dictionary,
+/// needed to reach at least `target_bits` of entropy. The inverse of
+/// `phrase_entropy_bits`.
+pub fn min_words_for_bits(target_bits: f64) -> usize {
+	(target_bits / (WORDS.len() as f64).log2()).ceil() as usize
 }
 
 /// Phrase Validation Error
@@ -47,6 +456,18 @@ pub enum Error {
 	PhraseTooShort(usize),
 	/// Phrase contains a word that doesn't come from our dictionary.
 	WordNotFromDictionary(String),
+	/// The phrase style used to generate the phrase can't be reversed for
+	/// validation (e.g. `Separator::None`, which loses the word boundaries).
+	StyleNotReversible,
+	/// Not enough dice rolls were supplied to produce the requested phrase; the
+	/// value is the minimum number of rolls needed, ignoring re-rolls caused by
+	/// out-of-range chunks.
+	NotEnoughDiceRolls(usize),
+	/// A dice roll value was outside the valid `1..=6` range.
+	InvalidDiceRoll(u8),
+	/// A mask template ended with a bare `?` or used an unrecognized
+	/// placeholder letter.
+	InvalidMask(String),
 }
 
 impl fmt::Display for Error {
@@ -54,6 +475,10 @@ impl fmt::Display for Error {
         match *self {
             Error::PhraseTooShort(len) => writeln!(fmt, "The phrase is too short ({})", len),
             Error::WordNotFromDictionary(ref word) => writeln!(fmt, "The word '{}' does not come from the dictionary.", word),
+            Error::StyleNotReversible => writeln!(fmt, "The phrase style cannot be reversed for validation."),
+            Error::NotEnoughDiceRolls(needed) => writeln!(fmt, "Not enough dice rolls were supplied (need at least {}).", needed),
+            Error::InvalidDiceRoll(roll) => writeln!(fmt, "The dice roll '{}' is not in the range 1..=6.", roll),
+            Error::InvalidMask(ref mask) => writeln!(fmt, "The mask '{}' is not a valid phrase template.", mask),
         }
     }
 }
@@ -62,28 +487,54 @@ impl fmt::Display for Error {
 /// 1. All the words are coming from the dictionary.
 /// 2. There are at least `expected_no_of_words` in the phrase.
 pub fn validate_phrase(phrase: &str, expected_no_of_words: usize) -> Result<(), Error> {
-	lazy_static::lazy_static! {
-		static ref WORD_SET: HashSet<&'static str> = WORDS.iter().cloned().collect();
-	}
+	builtin_wordlist().validate_phrase(phrase, expected_no_of_words)
+}
 
-	let mut len = 0;
-	for word in phrase.split_whitespace() {
-		len += 1;
-		if !WORD_SET.contains(word) {
-			return Err(Error::WordNotFromDictionary(word.into()));
-		}
-	}
+/// Generate a phrase styled per `style` from the built-in dictionary. See
+/// `PhraseStyle` for the available separators, capitalization modes, and
+/// appended digit/symbol groups.
+pub fn styled_phrase(no_of_words: usize, style: &PhraseStyle) -> String {
+	builtin_wordlist().styled_phrase(no_of_words, style)
+}
 
-	if len < expected_no_of_words {
-		return Err(Error::PhraseTooShort(len));
-	}
+/// Validates a phrase produced by `styled_phrase` against the built-in
+/// dictionary, using the given `style` to reverse the formatting first.
+pub fn validate_styled_phrase(phrase: &str, expected_no_of_words: usize, style: &PhraseStyle) -> Result<(), Error> {
+	builtin_wordlist().validate_styled_phrase(phrase, expected_no_of_words, style)
+}
+
+/// Generate a phrase from the built-in dictionary by deterministically mapping
+/// a sequence of physical d6 dice rolls to words. See
+/// `Wordlist::phrase_from_dice` for the rejection-sampling scheme and how many
+/// rolls a given word count needs.
+pub fn phrase_from_dice(rolls: &[u8], no_of_words: usize) -> Result<String, Error> {
+	builtin_wordlist().phrase_from_dice(rolls, no_of_words)
+}
 
-	return Ok(());
+/// Draw `samples` single-word picks from the built-in dictionary using `rng`
+/// and run a chi-square goodness-of-fit test against the uniform distribution.
+/// See `Wordlist::check_uniformity`.
+pub fn check_uniformity<R: Rng + ?Sized>(rng: &mut R, samples: usize) -> UniformityReport {
+	builtin_wordlist().check_uniformity(rng, samples)
+}
+
+/// Fill a mask template against the built-in dictionary. See
+/// `Wordlist::phrase_from_mask` for the placeholder syntax.
+pub fn phrase_from_mask<R: Rng + ?Sized>(mask: &str, rng: &mut R) -> Result<String, Error> {
+	builtin_wordlist().phrase_from_mask(mask, rng)
+}
+
+/// Compute the Shannon entropy, in bits, of phrases produced by
+/// `phrase_from_mask` against the built-in dictionary.
+pub fn mask_entropy_bits(mask: &str) -> Result<f64, Error> {
+	builtin_wordlist().mask_entropy_bits(mask)
 }
 
 #[cfg(test)]
 mod tests {
-	use super::{validate_phrase, random_phrase, Error};
+	use super::{validate_phrase, random_phrase, random_phrase_with, phrase_entropy_bits, entropy_bits_for_dict_len,
+		min_words_for_bits, styled_phrase, validate_styled_phrase, phrase_from_dice, check_uniformity, phrase_from_mask,
+		mask_entropy_bits, Wordlist, PhraseStyle, Separator, Capitalization, Error};
 
 	#[test]
 	fn should_produce_right_number_of_words() {
@@ -105,4 +556,144 @@ mod tests {
 		assert_eq!(validate_phrase(&p, 12), Err(Error::PhraseTooShort(10)));
 		assert_eq!(validate_phrase("xxx", 0), Err(Error::WordNotFromDictionary("xxx".into())));
 	}
+
+	#[test]
+	fn should_compute_phrase_entropy_bits() {
+		assert_eq!(entropy_bits_for_dict_len(12, 7530), 12.0 * (7530f64).log2());
+		assert_eq!(phrase_entropy_bits(12), entropy_bits_for_dict_len(12, super::WORDS.len()));
+	}
+
+	#[test]
+	fn should_compute_min_words_for_bits() {
+		let bits = phrase_entropy_bits(min_words_for_bits(128.0));
+		assert!(bits >= 128.0);
+		assert!(phrase_entropy_bits(min_words_for_bits(128.0) - 1) < 128.0);
+	}
+
+	#[test]
+	fn should_be_deterministic_given_the_same_seed() {
+		use rand::{rngs::StdRng, SeedableRng};
+
+		let mut rng_a = StdRng::seed_from_u64(42);
+		let mut rng_b = StdRng::seed_from_u64(42);
+		assert_eq!(random_phrase_with(&mut rng_a, 10), random_phrase_with(&mut rng_b, 10));
+	}
+
+	#[test]
+	fn should_build_a_custom_wordlist_from_lines() {
+		let list = Wordlist::from_lines("alpha\r\nbravo\ncharlie\n");
+		let p = list.random_phrase(3);
+		assert_eq!(list.validate_phrase(&p, 3), Ok(()));
+		assert_eq!(list.validate_phrase("delta", 1), Err(Error::WordNotFromDictionary("delta".into())));
+	}
+
+	#[test]
+	#[should_panic(expected = "Wordlist must contain at least one word")]
+	fn should_panic_building_an_empty_wordlist() {
+		Wordlist::from_lines("\n\n");
+	}
+
+	#[test]
+	fn builtin_wordlist_behaves_like_the_free_functions() {
+		let list = Wordlist::builtin();
+		let p = list.random_phrase(10);
+		assert_eq!(list.validate_phrase(&p, 10), Ok(()));
+		assert_eq!(validate_phrase(&p, 10), Ok(()));
+		assert_eq!(list.entropy_bits(10), phrase_entropy_bits(10));
+	}
+
+	#[test]
+	fn should_style_and_validate_a_hyphenated_phrase() {
+		let style = PhraseStyle { separator: Separator::Hyphen, ..PhraseStyle::default() };
+		let p = styled_phrase(4, &style);
+		assert_eq!(p.matches('-').count(), 3);
+		assert_eq!(validate_styled_phrase(&p, 4, &style), Ok(()));
+	}
+
+	#[test]
+	fn should_style_title_case_and_append_digits_and_symbols() {
+		let style = PhraseStyle {
+			separator: Separator::Space,
+			capitalization: Capitalization::Title,
+			append_digits: 2,
+			append_symbols: 1,
+		};
+		let p = styled_phrase(3, &style);
+		let first_word = p.split(' ').next().unwrap();
+		assert!(first_word.chars().next().unwrap().is_uppercase());
+		assert_eq!(validate_styled_phrase(&p, 3, &style), Ok(()));
+	}
+
+	#[test]
+	fn should_refuse_to_validate_a_non_reversible_style() {
+		let style = PhraseStyle { separator: Separator::None, ..PhraseStyle::default() };
+		let p = styled_phrase(3, &style);
+		assert_eq!(validate_styled_phrase(&p, 3, &style), Err(Error::StyleNotReversible));
+	}
+
+	#[test]
+	fn should_map_dice_rolls_to_words_rejecting_the_biased_tail() {
+		let list = Wordlist::from_lines("a\nb\nc\nd\ne");
+		// 5 words need 1 roll/word (6^1 >= 5); rolls of 6 land in the rejected
+		// tail [5, 6) and must be skipped before a valid roll is accepted.
+		assert_eq!(list.phrase_from_dice(&[6, 6, 3], 1), Ok("c".into()));
+	}
+
+	#[test]
+	fn should_report_insufficient_dice_rolls() {
+		let list = Wordlist::from_lines("a\nb\nc\nd\ne");
+		assert_eq!(list.phrase_from_dice(&[3], 2), Err(Error::NotEnoughDiceRolls(2)));
+	}
+
+	#[test]
+	fn should_reject_out_of_range_dice_rolls() {
+		let list = Wordlist::from_lines("a\nb\nc\nd\ne");
+		assert_eq!(list.phrase_from_dice(&[7], 1), Err(Error::InvalidDiceRoll(7)));
+	}
+
+	#[test]
+	fn builtin_dice_phrase_is_backed_by_the_free_function() {
+		let rolls = vec![1u8; 5 * 12];
+		assert!(phrase_from_dice(&rolls, 12).is_ok());
+	}
+
+	#[test]
+	fn uniformity_check_reports_expected_degrees_of_freedom() {
+		use rand::{rngs::StdRng, SeedableRng};
+
+		let mut rng = StdRng::seed_from_u64(7);
+		let report = check_uniformity(&mut rng, 200_000);
+		assert_eq!(report.degrees_of_freedom, super::WORDS.len() - 1);
+		// Not a strict statistical assertion - just a sanity bound that a uniform
+		// `gen_range` mapping doesn't blow up the statistic wildly.
+		assert!(report.chi_square < super::WORDS.len() as f64 * 2.0);
+	}
+
+	#[test]
+	fn should_fill_a_mask_template() {
+		use rand::{rngs::StdRng, SeedableRng};
+
+		let mut rng = StdRng::seed_from_u64(1);
+		let p = phrase_from_mask("?w-?w-?d?d?s", &mut rng).unwrap();
+		let parts: Vec<&str> = p.split('-').collect();
+		assert_eq!(parts.len(), 3);
+		assert_eq!(validate_phrase(parts[0], 1), Ok(()));
+		assert_eq!(validate_phrase(parts[1], 1), Ok(()));
+		assert_eq!(parts[2].chars().filter(|c| c.is_ascii_digit()).count(), 2);
+	}
+
+	#[test]
+	fn should_reject_an_invalid_mask() {
+		use rand::{rngs::StdRng, SeedableRng};
+
+		let mut rng = StdRng::seed_from_u64(1);
+		assert_eq!(phrase_from_mask("?w-?x", &mut rng), Err(Error::InvalidMask("?w-?x".into())));
+	}
+
+	#[test]
+	fn should_compute_mask_entropy_bits() {
+		assert_eq!(mask_entropy_bits("?d?d").unwrap(), 2.0 * 10f64.log2());
+		assert_eq!(mask_entropy_bits("?w-?w").unwrap(), 2.0 * phrase_entropy_bits(1));
+		assert_eq!(mask_entropy_bits("literal").unwrap(), 0.0);
+	}
 }